@@ -1,31 +1,156 @@
+use std::convert::TryFrom;
 use std::str::FromStr;
+use std::time::Duration;
 
 use lazy_static::lazy_static;
 use regex::Regex;
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::error::Error;
 use crate::TimeUnit;
 
+// Unit alternatives are tried left-to-right, so a literal that is a prefix
+// of another (e.g. "m" is a prefix of "mo" and "ms") must be listed after
+// it, or the engine commits to the short match first and leaves the rest
+// of the longer literal dangling.
 lazy_static! {
     static ref DURATION_REGEX: Regex =
-        Regex::new(r"^(?P<value>\d+)(?P<unit>ns|us|ms|s|m|h|d|w){1}$")
+        Regex::new(r"^(?P<sign>-)?(?P<value>\d+(?:\.\d+)?)(?P<unit>ns|us|ms|mo|s|m|h|d|w|y|c){1}$")
+            .expect("Regex compilation error");
+    static ref DURATION_SEGMENT_REGEX: Regex =
+        Regex::new(r"(?P<value>\d+(?:\.\d+)?)(?P<unit>ns|us|ms|mo|s|m|h|d|w|y|c)")
             .expect("Regex compilation error");
 }
 
 /// The number of seconds in a minute.
-const SECS_PER_MINUTE: u64 = 60;
+pub(crate) const SECS_PER_MINUTE: u64 = 60;
 /// The number of seconds in an hour.
-const SECS_PER_HOUR: u64 = 3600;
+pub(crate) const SECS_PER_HOUR: u64 = 3600;
 /// The number of (non-leap) seconds in days.
-const SECS_PER_DAY: u64 = 86_400;
+pub(crate) const SECS_PER_DAY: u64 = 86_400;
 /// The number of (non-leap) seconds in a week.
-const SECS_PER_WEEK: u64 = 604_800;
+pub(crate) const SECS_PER_WEEK: u64 = 604_800;
+/// The number of seconds in a month, approximated as a fixed 30 days.
+pub(crate) const SECS_PER_MONTH: u64 = 30 * SECS_PER_DAY;
+/// The number of seconds in a year, approximated as a fixed 365 days.
+pub(crate) const SECS_PER_YEAR: u64 = 365 * SECS_PER_DAY;
+/// The number of seconds in a century, approximated as a fixed 100 years.
+pub(crate) const SECS_PER_CENTURY: u64 = 100 * SECS_PER_YEAR;
+/// The number of nanoseconds in a second.
+pub(crate) const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+/// All [`TimeUnit`] variants, largest first, used to greedily decompose a
+/// [`Duration`] into a compound human-readable string.
+const UNITS_DESC: [TimeUnit; 11] = [
+    TimeUnit::Century,
+    TimeUnit::Year,
+    TimeUnit::Month,
+    TimeUnit::Week,
+    TimeUnit::Day,
+    TimeUnit::Hour,
+    TimeUnit::Minute,
+    TimeUnit::Second,
+    TimeUnit::Millisecond,
+    TimeUnit::Microsecond,
+    TimeUnit::Nanosecond,
+];
+
+/// Scales `value` by `multiplier` and divides by `round_scale`, rounding to
+/// the nearest integer, using checked arithmetic throughout so an
+/// attacker-controlled `value` (e.g. parsed from an arbitrarily long
+/// fractional digit string) cannot overflow `u128` silently or panic.
+pub(crate) fn checked_scaled_round(
+    value: u128,
+    multiplier: u128,
+    round_scale: u128,
+) -> Result<u128, Error> {
+    let product = value.checked_mul(multiplier).ok_or(Error::Overflow)?;
+    let rounded = product
+        .checked_add(round_scale / 2)
+        .ok_or(Error::Overflow)?;
+    Ok(rounded / round_scale)
+}
+
+/// Parses a decimal value like `"1"` or `"1.5"` into its integer part and a
+/// fractional-nanosecond remainder (rounded to the nearest nanosecond) for
+/// `unit`, using integer-only arithmetic to avoid floating-point drift.
+fn parse_value(raw: &str, unit: TimeUnit) -> Result<(u64, u64), Error> {
+    let (int_part, frac_part) = match raw.split_once('.') {
+        Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+        None => (raw, None),
+    };
+    let value: u64 = int_part
+        .parse()
+        .map_err(|_| Error::Syntax(format!("Invalid duration value '{}'", raw)))?;
+    let frac_nanos = match frac_part {
+        Some(frac) if !frac.is_empty() => {
+            let frac_value: u128 = frac
+                .parse()
+                .map_err(|_| Error::Syntax(format!("Invalid duration value '{}'", raw)))?;
+            let scale = 10u128
+                .checked_pow(frac.len() as u32)
+                .ok_or(Error::Overflow)?;
+            u64::try_from(checked_scaled_round(frac_value, unit_nanos(unit), scale)?)
+                .map_err(|_| Error::Overflow)?
+        }
+        _ => 0,
+    };
+    Ok((value, frac_nanos))
+}
+
+/// Returns how many nanoseconds fit in a single unit of `unit`.
+fn unit_nanos(unit: TimeUnit) -> u128 {
+    match unit {
+        TimeUnit::Nanosecond => 1,
+        TimeUnit::Microsecond => 1_000,
+        TimeUnit::Millisecond => 1_000_000,
+        TimeUnit::Second => NANOS_PER_SECOND,
+        TimeUnit::Minute => NANOS_PER_SECOND * SECS_PER_MINUTE as u128,
+        TimeUnit::Hour => NANOS_PER_SECOND * SECS_PER_HOUR as u128,
+        TimeUnit::Day => NANOS_PER_SECOND * SECS_PER_DAY as u128,
+        TimeUnit::Week => NANOS_PER_SECOND * SECS_PER_WEEK as u128,
+        TimeUnit::Month => NANOS_PER_SECOND * SECS_PER_MONTH as u128,
+        TimeUnit::Year => NANOS_PER_SECOND * SECS_PER_YEAR as u128,
+        TimeUnit::Century => NANOS_PER_SECOND * SECS_PER_CENTURY as u128,
+    }
+}
+
+/// How many decimal digits [`format_fraction`] emits at most; enough to
+/// recover an exact nanosecond count for every unit this crate supports
+/// (the largest, [`TimeUnit::Century`], needs 19).
+const MAX_FRACTIONAL_DIGITS: u32 = 19;
+
+/// Renders `frac_nanos` (the fractional-nanosecond remainder of a
+/// [`DurationUnit`]) as the decimal digits that follow the `.` in e.g.
+/// `"1.5h"`, using integer-only long division to avoid floating-point
+/// drift. Returns `None` if there is no fractional part.
+fn format_fraction(frac_nanos: u64, unit: TimeUnit) -> Option<String> {
+    if frac_nanos == 0 {
+        return None;
+    }
+    let denom = unit_nanos(unit);
+    let mut remainder = frac_nanos as u128;
+    let mut digits = String::new();
+    for _ in 0..MAX_FRACTIONAL_DIGITS {
+        remainder *= 10;
+        let digit = remainder / denom;
+        digits.push((b'0' + digit as u8) as char);
+        remainder %= denom;
+        if remainder == 0 {
+            break;
+        }
+    }
+    Some(digits)
+}
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
 pub struct DurationUnit {
     value: u64,
     unit: TimeUnit,
+    /// Nanoseconds contributed by a fractional value, e.g. the `.5` in `"1.5h"`.
+    frac_nanos: u64,
+    /// Whether this duration is negative, e.g. the `-` in `"-30m"`.
+    negative: bool,
 }
 
 impl FromStr for DurationUnit {
@@ -36,10 +161,17 @@ impl FromStr for DurationUnit {
             let caps = DURATION_REGEX
                 .captures(s)
                 .ok_or_else(|| Error::StringDoesNotMatchRegex)?;
-            let value = caps.name("value").unwrap().as_str().parse().unwrap();
+            let negative = caps.name("sign").is_some();
+            let raw_value = caps.name("value").unwrap().as_str();
             let time_unit = caps.name("unit").unwrap().as_str();
             let unit = time_unit.parse::<TimeUnit>()?;
-            Ok(Self { value, unit })
+            let (value, frac_nanos) = parse_value(raw_value, unit)?;
+            Ok(Self {
+                value,
+                unit,
+                frac_nanos,
+                negative,
+            })
         } else {
             Err(Error::Syntax(
                 "Current string is not correct duration unit value".to_owned(),
@@ -51,7 +183,12 @@ impl FromStr for DurationUnit {
 impl DurationUnit {
     /// Creates a new `DurationUnit` from the specified value and time unit.
     pub fn new(value: u64, unit: TimeUnit) -> Self {
-        Self { value, unit }
+        Self {
+            value,
+            unit,
+            frac_nanos: 0,
+            negative: false,
+        }
     }
 
     /// Returns the time unit of this duration unit.
@@ -59,49 +196,61 @@ impl DurationUnit {
         self.unit
     }
 
+    /// Returns whether this duration is negative, e.g. parsed from `"-30m"`.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns the exact number of nanoseconds this duration unit represents
+    /// (unsigned magnitude), including any fractional component (e.g. the
+    /// `.5` in `"1.5h"`).
+    pub fn as_nanos(&self) -> u128 {
+        self.value as u128 * unit_nanos(self.unit) + self.frac_nanos as u128
+    }
+
     pub fn as_secs(&self) -> u64 {
-        match self.unit {
-            TimeUnit::Nanosecond => self.value / 1_000_000_000,
-            TimeUnit::Microsecond => self.value / 1_000_000,
-            TimeUnit::Millisecond => self.value / 1_000,
-            TimeUnit::Second => self.value,
-            TimeUnit::Minute => self.value * SECS_PER_MINUTE,
-            TimeUnit::Hour => self.value * SECS_PER_HOUR,
-            TimeUnit::Day => self.value * SECS_PER_DAY,
-            TimeUnit::Week => self.value * SECS_PER_WEEK,
+        (self.as_nanos() / NANOS_PER_SECOND) as u64
+    }
+
+    /// Fallibly converts to a [`Duration`], returning [`Error::Overflow`]
+    /// instead of silently wrapping when `value * unit` would exceed
+    /// `u64::MAX` (e.g. attacker-controlled config like `"9999999999999999999w"`),
+    /// and [`Error::NegativeDuration`] since [`Duration`] is unsigned and
+    /// cannot represent a negative span like `"-30m"`.
+    pub fn checked_duration(&self) -> Result<Duration, Error> {
+        if self.negative {
+            return Err(Error::NegativeDuration);
         }
+        let total_nanos = self.as_nanos();
+        let secs = u64::try_from(total_nanos / NANOS_PER_SECOND).map_err(|_| Error::Overflow)?;
+        let nanos = (total_nanos % NANOS_PER_SECOND) as u32;
+        Ok(Duration::new(secs, nanos))
     }
 }
 
-impl From<DurationUnit> for std::time::Duration {
-    fn from(duration_unit: DurationUnit) -> Self {
-        match duration_unit.unit {
-            TimeUnit::Nanosecond => std::time::Duration::from_nanos(duration_unit.value),
-            TimeUnit::Microsecond => std::time::Duration::from_micros(duration_unit.value),
-            TimeUnit::Millisecond => std::time::Duration::from_millis(duration_unit.value),
-            TimeUnit::Second => std::time::Duration::from_secs(duration_unit.value),
-            TimeUnit::Minute => {
-                std::time::Duration::from_secs(duration_unit.value * SECS_PER_MINUTE)
-            }
-            TimeUnit::Hour => std::time::Duration::from_secs(duration_unit.value * SECS_PER_HOUR),
-            TimeUnit::Day => std::time::Duration::from_secs(duration_unit.value * SECS_PER_DAY),
-            TimeUnit::Week => std::time::Duration::from_secs(duration_unit.value * SECS_PER_WEEK),
-        }
+// Note: there is deliberately no infallible `From<DurationUnit> for Duration`
+// impl. `value * unit` can exceed `u64::MAX` for attacker-controlled config
+// (e.g. `"9999999999999999999w"`), so the only conversion offered is the
+// checked one below; the standard library's blanket
+// `impl<T, U> TryFrom<U> for T where U: Into<T>` would otherwise conflict
+// with it anyway.
+impl TryFrom<DurationUnit> for Duration {
+    type Error = Error;
+
+    fn try_from(duration_unit: DurationUnit) -> Result<Self, Self::Error> {
+        duration_unit.checked_duration()
     }
 }
 
 #[cfg(feature = "chrono")]
 impl From<DurationUnit> for chrono::Duration {
     fn from(duration_unit: DurationUnit) -> Self {
-        match duration_unit.unit {
-            TimeUnit::Nanosecond => chrono::Duration::nanoseconds(duration_unit.value as i64),
-            TimeUnit::Microsecond => chrono::Duration::microseconds(duration_unit.value as i64),
-            TimeUnit::Millisecond => chrono::Duration::milliseconds(duration_unit.value as i64),
-            TimeUnit::Second => chrono::Duration::seconds(duration_unit.value as i64),
-            TimeUnit::Minute => chrono::Duration::minutes(duration_unit.value as i64),
-            TimeUnit::Hour => chrono::Duration::hours(duration_unit.value as i64),
-            TimeUnit::Day => chrono::Duration::days(duration_unit.value as i64),
-            TimeUnit::Week => chrono::Duration::weeks(duration_unit.value as i64),
+        let negative = duration_unit.negative;
+        let magnitude = chrono::Duration::nanoseconds(duration_unit.as_nanos() as i64);
+        if negative {
+            -magnitude
+        } else {
+            magnitude
         }
     }
 }
@@ -117,6 +266,163 @@ impl<'a> Deserialize<'a> for DurationUnit {
     }
 }
 
+impl Serialize for DurationUnit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let sign = if self.negative { "-" } else { "" };
+        match format_fraction(self.frac_nanos, self.unit) {
+            Some(frac) => serializer.serialize_str(&format!(
+                "{}{}.{}{}",
+                sign,
+                self.value,
+                frac,
+                self.unit.as_str()
+            )),
+            None => {
+                serializer.serialize_str(&format!("{}{}{}", sign, self.value, self.unit.as_str()))
+            }
+        }
+    }
+}
+
+/// A duration made up of one or more `value+unit` segments that are summed
+/// together, e.g. `"1h30m15s"` or `"1w2d"`.
+///
+/// Unlike [`DurationUnit`], which holds exactly one `value+unit` pair, this
+/// type accepts any number of segments and accumulates them into a single
+/// [`Duration`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct CompoundDuration(Duration);
+
+impl CompoundDuration {
+    /// Consumes this `CompoundDuration`, returning the summed [`Duration`].
+    pub fn into_duration(self) -> Duration {
+        self.0
+    }
+}
+
+impl FromStr for CompoundDuration {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut covered = 0;
+        let mut total_nanos: u128 = 0;
+        let mut matched_any = false;
+        for caps in DURATION_SEGMENT_REGEX.captures_iter(s) {
+            let whole = caps.get(0).unwrap();
+            if whole.start() != covered {
+                return Err(Error::Syntax(
+                    "Current string is not correct duration unit value".to_owned(),
+                ));
+            }
+            let raw_value = caps.name("value").unwrap().as_str();
+            let unit = caps.name("unit").unwrap().as_str().parse::<TimeUnit>()?;
+            let (value, frac_nanos) = parse_value(raw_value, unit)?;
+            let segment_nanos = (value as u128)
+                .checked_mul(unit_nanos(unit))
+                .and_then(|n| n.checked_add(frac_nanos as u128))
+                .ok_or(Error::Overflow)?;
+            total_nanos = total_nanos
+                .checked_add(segment_nanos)
+                .ok_or(Error::Overflow)?;
+            covered = whole.end();
+            matched_any = true;
+        }
+        if !matched_any || covered != s.len() {
+            return Err(Error::Syntax(
+                "Current string is not correct duration unit value".to_owned(),
+            ));
+        }
+        let secs = u64::try_from(total_nanos / NANOS_PER_SECOND).map_err(|_| Error::Overflow)?;
+        let subsec_nanos = (total_nanos % NANOS_PER_SECOND) as u32;
+        Ok(Self(Duration::new(secs, subsec_nanos)))
+    }
+}
+
+impl From<CompoundDuration> for Duration {
+    fn from(compound: CompoundDuration) -> Self {
+        compound.0
+    }
+}
+
+impl<'a> Deserialize<'a> for CompoundDuration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+impl Serialize for CompoundDuration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&DurationFormatter::default().format(&self.0))
+    }
+}
+
+/// Builds a compound, human-readable rendering of a [`std::time::Duration`],
+/// e.g. `"1w2d3h"`.
+///
+/// Components are emitted largest-unit-first and only when nonzero, down to
+/// (and including) [`smallest_unit`](DurationFormatter::smallest_unit), which
+/// defaults to [`TimeUnit::Millisecond`]. Lower the smallest unit to losslessly
+/// round-trip values with finer precision, e.g. `TimeUnit::Nanosecond`.
+#[derive(Debug, Clone)]
+pub struct DurationFormatter {
+    smallest_unit: TimeUnit,
+}
+
+impl Default for DurationFormatter {
+    fn default() -> Self {
+        Self {
+            smallest_unit: TimeUnit::Millisecond,
+        }
+    }
+}
+
+impl DurationFormatter {
+    /// Creates a new formatter with the default smallest unit (milliseconds).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the smallest unit that may appear in the rendered string.
+    pub fn smallest_unit(mut self, unit: TimeUnit) -> Self {
+        self.smallest_unit = unit;
+        self
+    }
+
+    /// Renders `duration` as a compound duration string.
+    pub fn format(&self, duration: &Duration) -> String {
+        let mut remaining = duration.as_nanos();
+        let mut out = String::new();
+        for unit in UNITS_DESC {
+            if unit < self.smallest_unit {
+                break;
+            }
+            let nanos = unit_nanos(unit);
+            let value = remaining / nanos;
+            if value > 0 {
+                out.push_str(&value.to_string());
+                out.push_str(unit.as_str());
+                remaining -= value * nanos;
+            }
+        }
+        if out.is_empty() {
+            out.push('0');
+            out.push_str(self.smallest_unit.as_str());
+        }
+        out
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -148,53 +454,161 @@ mod tests {
         assert_eq!(duration_unit.value, 1);
         assert_eq!(duration_unit.unit, TimeUnit::Week);
 
+        let duration_unit = "6mo".parse::<DurationUnit>().unwrap();
+        assert_eq!(duration_unit.value, 6);
+        assert_eq!(duration_unit.unit, TimeUnit::Month);
+
+        let duration_unit = "1y".parse::<DurationUnit>().unwrap();
+        assert_eq!(duration_unit.value, 1);
+        assert_eq!(duration_unit.unit, TimeUnit::Year);
+
+        let duration_unit = "2c".parse::<DurationUnit>().unwrap();
+        assert_eq!(duration_unit.value, 2);
+        assert_eq!(duration_unit.unit, TimeUnit::Century);
+
         let duration_unit = "invalid".parse::<DurationUnit>();
         assert!(duration_unit.is_err());
     }
 
     #[test]
-    fn test_duration_unit_into_duration() {
+    fn test_duration_unit_from_str_fractional() {
+        let duration_unit = "1.5h".parse::<DurationUnit>().unwrap();
+        assert_eq!(duration_unit.value, 1);
+        assert_eq!(duration_unit.unit, TimeUnit::Hour);
+        assert_eq!(
+            Duration::try_from(duration_unit).unwrap(),
+            Duration::from_secs(5400)
+        );
+
+        let duration_unit = "0.25d".parse::<DurationUnit>().unwrap();
+        assert_eq!(
+            Duration::try_from(duration_unit).unwrap(),
+            Duration::from_secs(SECS_PER_DAY / 4)
+        );
+
+        let duration_unit = "0.5s".parse::<DurationUnit>().unwrap();
+        assert_eq!(
+            Duration::try_from(duration_unit).unwrap(),
+            Duration::from_millis(500)
+        );
+
+        assert!("1.5".parse::<DurationUnit>().is_err());
+        assert!("h".parse::<DurationUnit>().is_err());
+    }
+
+    #[test]
+    fn test_duration_unit_from_str_rejects_fractional_overflow_instead_of_panicking() {
+        assert_eq!(
+            "1.999999999999999999999c".parse::<DurationUnit>(),
+            Err(Error::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_duration_unit_from_str_negative() {
+        let duration_unit = "-30m".parse::<DurationUnit>().unwrap();
+        assert_eq!(duration_unit.value, 30);
+        assert_eq!(duration_unit.unit, TimeUnit::Minute);
+        assert!(duration_unit.is_negative());
+
+        let duration_unit = "10s".parse::<DurationUnit>().unwrap();
+        assert!(!duration_unit.is_negative());
+
+        assert!("--30m".parse::<DurationUnit>().is_err());
+    }
+
+    #[test]
+    fn test_checked_duration_rejects_negative() {
+        let duration_unit = "-30m".parse::<DurationUnit>().unwrap();
+        assert_eq!(duration_unit.checked_duration(), Err(Error::NegativeDuration));
+        assert_eq!(
+            Duration::try_from(duration_unit),
+            Err(Error::NegativeDuration)
+        );
+    }
+
+    #[test]
+    fn test_duration_unit_try_into_duration() {
         let duration_unit = DurationUnit {
             value: 10,
             unit: TimeUnit::Second,
+            frac_nanos: 0,
+            negative: false,
         };
-        let duration: Duration = duration_unit.into();
+        let duration = Duration::try_from(duration_unit).unwrap();
         assert_eq!(duration, Duration::from_secs(10));
 
         let duration_unit = DurationUnit {
             value: 500,
             unit: TimeUnit::Millisecond,
+            frac_nanos: 0,
+            negative: false,
         };
-        let duration: Duration = duration_unit.into();
+        let duration = Duration::try_from(duration_unit).unwrap();
         assert_eq!(duration, Duration::from_millis(500));
 
         let duration_unit = DurationUnit {
             value: 1,
             unit: TimeUnit::Hour,
+            frac_nanos: 0,
+            negative: false,
         };
-        let duration: Duration = duration_unit.into();
+        let duration = Duration::try_from(duration_unit).unwrap();
         assert_eq!(duration, Duration::from_secs(3600));
 
         let duration_unit = DurationUnit {
             value: 100,
             unit: TimeUnit::Microsecond,
+            frac_nanos: 0,
+            negative: false,
         };
-        let duration: Duration = duration_unit.into();
+        let duration = Duration::try_from(duration_unit).unwrap();
         assert_eq!(duration, Duration::from_micros(100));
 
         let duration_unit = DurationUnit {
             value: 2,
             unit: TimeUnit::Day,
+            frac_nanos: 0,
+            negative: false,
         };
-        let duration: Duration = duration_unit.into();
+        let duration = Duration::try_from(duration_unit).unwrap();
         assert_eq!(duration, Duration::from_secs(172_800));
 
         let duration_unit = DurationUnit {
             value: 1,
             unit: TimeUnit::Week,
+            frac_nanos: 0,
+            negative: false,
         };
-        let duration: Duration = duration_unit.into();
+        let duration = Duration::try_from(duration_unit).unwrap();
         assert_eq!(duration, Duration::from_secs(604_800));
+
+        let duration_unit = DurationUnit {
+            value: 6,
+            unit: TimeUnit::Month,
+            frac_nanos: 0,
+            negative: false,
+        };
+        let duration = Duration::try_from(duration_unit).unwrap();
+        assert_eq!(duration, Duration::from_secs(6 * SECS_PER_MONTH));
+
+        let duration_unit = DurationUnit {
+            value: 1,
+            unit: TimeUnit::Year,
+            frac_nanos: 0,
+            negative: false,
+        };
+        let duration = Duration::try_from(duration_unit).unwrap();
+        assert_eq!(duration, Duration::from_secs(SECS_PER_YEAR));
+
+        let duration_unit = DurationUnit {
+            value: 1,
+            unit: TimeUnit::Century,
+            frac_nanos: 0,
+            negative: false,
+        };
+        let duration = Duration::try_from(duration_unit).unwrap();
+        assert_eq!(duration, Duration::from_secs(SECS_PER_CENTURY));
     }
 
     #[cfg(feature = "chrono")]
@@ -203,6 +617,8 @@ mod tests {
         let duration_unit = DurationUnit {
             value: 10,
             unit: TimeUnit::Second,
+            frac_nanos: 0,
+            negative: false,
         };
         let duration: chrono::Duration = duration_unit.into();
         assert_eq!(duration, chrono::Duration::seconds(10));
@@ -210,6 +626,8 @@ mod tests {
         let duration_unit = DurationUnit {
             value: 500,
             unit: TimeUnit::Millisecond,
+            frac_nanos: 0,
+            negative: false,
         };
         let duration: chrono::Duration = duration_unit.into();
         assert_eq!(duration, chrono::Duration::milliseconds(500));
@@ -217,6 +635,8 @@ mod tests {
         let duration_unit = DurationUnit {
             value: 1,
             unit: TimeUnit::Hour,
+            frac_nanos: 0,
+            negative: false,
         };
         let duration: chrono::Duration = duration_unit.into();
         assert_eq!(duration, chrono::Duration::hours(1));
@@ -224,6 +644,8 @@ mod tests {
         let duration_unit = DurationUnit {
             value: 100,
             unit: TimeUnit::Microsecond,
+            frac_nanos: 0,
+            negative: false,
         };
         let duration: chrono::Duration = duration_unit.into();
         assert_eq!(duration, chrono::Duration::microseconds(100));
@@ -231,6 +653,8 @@ mod tests {
         let duration_unit = DurationUnit {
             value: 2,
             unit: TimeUnit::Day,
+            frac_nanos: 0,
+            negative: false,
         };
         let duration: chrono::Duration = duration_unit.into();
         assert_eq!(duration, chrono::Duration::days(2));
@@ -238,11 +662,21 @@ mod tests {
         let duration_unit = DurationUnit {
             value: 1,
             unit: TimeUnit::Week,
+            frac_nanos: 0,
+            negative: false,
         };
         let duration: chrono::Duration = duration_unit.into();
         assert_eq!(duration, chrono::Duration::weeks(1));
     }
 
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_duration_unit_into_chrono_duration_negative() {
+        let duration_unit = "-30m".parse::<DurationUnit>().unwrap();
+        let duration: chrono::Duration = duration_unit.into();
+        assert_eq!(duration, chrono::Duration::minutes(-30));
+    }
+
     #[test]
     fn test_deseialize_duration_unit() {
         let duration_unit = serde_json::from_str::<DurationUnit>("\"10s\"").unwrap();
@@ -272,4 +706,193 @@ mod tests {
         let duration_unit = serde_json::from_str::<DurationUnit>("\"invalid\"");
         assert!(duration_unit.is_err());
     }
+
+    #[test]
+    fn test_checked_duration() {
+        let duration_unit = DurationUnit::new(10, TimeUnit::Second);
+        assert_eq!(
+            duration_unit.checked_duration().unwrap(),
+            Duration::from_secs(10)
+        );
+
+        let duration_unit = DurationUnit::new(1, TimeUnit::Week);
+        assert_eq!(
+            duration_unit.checked_duration().unwrap(),
+            Duration::from_secs(SECS_PER_WEEK)
+        );
+
+        let duration_unit = DurationUnit::new(u64::MAX, TimeUnit::Week);
+        assert_eq!(duration_unit.checked_duration(), Err(Error::Overflow));
+
+        let duration_unit = DurationUnit::new(u64::MAX, TimeUnit::Nanosecond);
+        assert!(duration_unit.checked_duration().is_ok());
+
+        let duration_unit = "1.5h".parse::<DurationUnit>().unwrap();
+        assert_eq!(
+            duration_unit.checked_duration().unwrap(),
+            Duration::from_secs(5400)
+        );
+    }
+
+    #[test]
+    fn test_try_from_duration_unit() {
+        let duration_unit = DurationUnit::new(2, TimeUnit::Day);
+        let duration = Duration::try_from(duration_unit).unwrap();
+        assert_eq!(duration, Duration::from_secs(172_800));
+
+        let duration_unit = DurationUnit::new(u64::MAX, TimeUnit::Week);
+        assert_eq!(Duration::try_from(duration_unit), Err(Error::Overflow));
+    }
+
+    #[test]
+    fn test_compound_duration_from_str() {
+        let compound = "1h30m".parse::<CompoundDuration>().unwrap();
+        assert_eq!(compound.into_duration(), Duration::from_secs(5400));
+
+        let compound = "1h30m15s".parse::<CompoundDuration>().unwrap();
+        assert_eq!(compound.into_duration(), Duration::from_secs(5415));
+
+        let compound = "1w2d3h".parse::<CompoundDuration>().unwrap();
+        assert_eq!(
+            compound.into_duration(),
+            Duration::from_secs(SECS_PER_WEEK + 2 * SECS_PER_DAY + 3 * SECS_PER_HOUR)
+        );
+
+        // A single segment still parses, matching `DurationUnit`'s behavior.
+        let compound = "10s".parse::<CompoundDuration>().unwrap();
+        assert_eq!(compound.into_duration(), Duration::from_secs(10));
+
+        assert!("1h30".parse::<CompoundDuration>().is_err());
+        assert!("1h foo 30m".parse::<CompoundDuration>().is_err());
+        assert!("".parse::<CompoundDuration>().is_err());
+    }
+
+    #[test]
+    fn test_compound_duration_from_str_month_segment() {
+        // "mo" shares a prefix with "m" (minute); the unit regex must try
+        // "mo" first or this dangles on the trailing "o".
+        let compound = "1mo".parse::<CompoundDuration>().unwrap();
+        assert_eq!(compound.into_duration(), Duration::from_secs(SECS_PER_MONTH));
+
+        let compound = "1mo2d".parse::<CompoundDuration>().unwrap();
+        assert_eq!(
+            compound.into_duration(),
+            Duration::from_secs(SECS_PER_MONTH + 2 * SECS_PER_DAY)
+        );
+    }
+
+    #[test]
+    fn test_compound_duration_from_str_fractional() {
+        let compound = "1.5h30m".parse::<CompoundDuration>().unwrap();
+        assert_eq!(compound.into_duration(), Duration::from_secs(7200));
+    }
+
+    #[test]
+    fn test_compound_duration_from_str_rejects_accumulator_overflow() {
+        let segment = format!("{}c", u64::MAX);
+        let overflowing = segment.repeat(10);
+        assert_eq!(
+            overflowing.parse::<CompoundDuration>(),
+            Err(Error::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_deserialize_compound_duration() {
+        let compound = serde_json::from_str::<CompoundDuration>("\"1h30m15s\"").unwrap();
+        assert_eq!(compound.into_duration(), Duration::from_secs(5415));
+
+        assert!(serde_json::from_str::<CompoundDuration>("\"1h30\"").is_err());
+    }
+
+    #[test]
+    fn test_serialize_compound_duration() {
+        let compound = "1h30m15s".parse::<CompoundDuration>().unwrap();
+        assert_eq!(
+            serde_json::to_string(&compound).unwrap(),
+            "\"1h30m15s\""
+        );
+    }
+
+    #[test]
+    fn test_compound_duration_round_trips_through_serde() {
+        let original = "1h30m15s".parse::<CompoundDuration>().unwrap();
+        let json = serde_json::to_string(&original).unwrap();
+        let round_tripped = serde_json::from_str::<CompoundDuration>(&json).unwrap();
+        assert_eq!(original, round_tripped);
+    }
+
+    #[test]
+    fn test_serialize_duration_unit() {
+        let duration_unit = DurationUnit::new(10, TimeUnit::Second);
+        assert_eq!(serde_json::to_string(&duration_unit).unwrap(), "\"10s\"");
+
+        let duration_unit = DurationUnit::new(1, TimeUnit::Week);
+        assert_eq!(serde_json::to_string(&duration_unit).unwrap(), "\"1w\"");
+    }
+
+    #[test]
+    fn test_serialize_duration_unit_negative() {
+        let duration_unit = "-30m".parse::<DurationUnit>().unwrap();
+        assert_eq!(serde_json::to_string(&duration_unit).unwrap(), "\"-30m\"");
+    }
+
+    #[test]
+    fn test_serialize_duration_unit_fractional() {
+        let duration_unit = "1.5h".parse::<DurationUnit>().unwrap();
+        assert_eq!(serde_json::to_string(&duration_unit).unwrap(), "\"1.5h\"");
+
+        let duration_unit = "0.25d".parse::<DurationUnit>().unwrap();
+        assert_eq!(serde_json::to_string(&duration_unit).unwrap(), "\"0.25d\"");
+
+        let duration_unit = "-1.5h".parse::<DurationUnit>().unwrap();
+        assert_eq!(serde_json::to_string(&duration_unit).unwrap(), "\"-1.5h\"");
+    }
+
+    #[test]
+    fn test_duration_formatter_default() {
+        let formatter = DurationFormatter::default();
+        assert_eq!(
+            formatter.format(&Duration::from_secs(
+                SECS_PER_WEEK + 2 * SECS_PER_DAY + 3 * SECS_PER_HOUR
+            )),
+            "1w2d3h"
+        );
+        assert_eq!(formatter.format(&Duration::from_secs(90)), "1m30s");
+        assert_eq!(formatter.format(&Duration::from_millis(1500)), "1s500ms");
+        assert_eq!(formatter.format(&Duration::from_secs(0)), "0ms");
+    }
+
+    #[test]
+    fn test_duration_formatter_default_uses_month_year_century() {
+        let formatter = DurationFormatter::default();
+        assert_eq!(
+            formatter.format(&Duration::from_secs(SECS_PER_MONTH + 2 * SECS_PER_DAY)),
+            "1mo2d"
+        );
+        assert_eq!(
+            formatter.format(&Duration::from_secs(SECS_PER_YEAR)),
+            "1y"
+        );
+        assert_eq!(
+            formatter.format(&Duration::from_secs(SECS_PER_CENTURY)),
+            "1c"
+        );
+    }
+
+    #[test]
+    fn test_duration_formatter_smallest_unit() {
+        let formatter = DurationFormatter::new().smallest_unit(TimeUnit::Nanosecond);
+        assert_eq!(
+            formatter.format(&Duration::from_nanos(1_500_000_250)),
+            "1s500ms250ns"
+        );
+        assert_eq!(
+            formatter
+                .clone()
+                .smallest_unit(TimeUnit::Second)
+                .format(&Duration::from_millis(500)),
+            "0s"
+        );
+    }
 }
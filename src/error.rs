@@ -8,6 +8,8 @@ pub enum Error {
     NoUnitProvided,
     NoValueProvided,
     StringDoesNotMatchRegex,
+    Overflow,
+    NegativeDuration,
 }
 
 impl StdError for Error {}
@@ -20,6 +22,10 @@ impl Display for Error {
             Error::NoUnitProvided => f.write_str("No unit provided"),
             Error::NoValueProvided => f.write_str("No value provided"),
             Error::StringDoesNotMatchRegex => f.write_str("String does not match regex"),
+            Error::Overflow => f.write_str("Duration value overflowed while being computed"),
+            Error::NegativeDuration => {
+                f.write_str("Negative duration cannot be represented as std::time::Duration")
+            }
         }
     }
 }
@@ -0,0 +1,327 @@
+//! ISO 8601 / xsd:duration parsing and formatting, e.g. `"PT1H30M"`, `"P1W"`,
+//! `"P3DT4H"`.
+//!
+//! The supported grammar is `P[nW]` or `P[nD]T[nH][nM][nS]`: `T` separates
+//! the date part (days) from the time part (hours/minutes/seconds), each
+//! component is an integer (the seconds component may be fractional)
+//! followed by its designator, designators must appear in the canonical
+//! order `D, H, M, S`, and the week form cannot be mixed with the others.
+//! At least one component must be present.
+
+use std::convert::TryFrom;
+use std::time::Duration;
+
+use serde::{Deserialize, Deserializer, Serializer};
+
+use crate::durationunit::{checked_scaled_round, NANOS_PER_SECOND, SECS_PER_DAY, SECS_PER_WEEK};
+use crate::error::Error;
+
+const NANOS_PER_HOUR: u128 = NANOS_PER_SECOND * 3600;
+const NANOS_PER_MINUTE: u128 = NANOS_PER_SECOND * 60;
+
+/// Parses an ISO 8601 / xsd:duration string into a [`Duration`].
+pub fn parse(s: &str) -> Result<Duration, Error> {
+    let body = s
+        .strip_prefix('P')
+        .ok_or_else(|| Error::Syntax("ISO 8601 duration must start with 'P'".to_owned()))?;
+    if body.is_empty() {
+        return Err(Error::Syntax(
+            "ISO 8601 duration must contain at least one component".to_owned(),
+        ));
+    }
+
+    if let Some(weeks) = body.strip_suffix('W') {
+        if weeks.is_empty() || !weeks.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(Error::Syntax(format!(
+                "Invalid ISO 8601 week component '{}W'",
+                weeks
+            )));
+        }
+        let weeks: u64 = weeks.parse().map_err(|_| {
+            Error::Syntax(format!("Invalid ISO 8601 week component '{}W'", weeks))
+        })?;
+        let secs = weeks.checked_mul(SECS_PER_WEEK).ok_or(Error::Overflow)?;
+        return Ok(Duration::from_secs(secs));
+    }
+
+    let (date_part, time_part) = match body.split_once('T') {
+        Some((date, time)) => (date, Some(time)),
+        None => (body, None),
+    };
+    if date_part.contains('W') {
+        return Err(Error::Syntax(
+            "ISO 8601 week component cannot be combined with other components".to_owned(),
+        ));
+    }
+
+    let mut total_nanos: u128 = 0;
+    let mut has_component = false;
+
+    if !date_part.is_empty() {
+        let days = parse_designator(date_part, 'D')?;
+        let day_nanos = (days as u128)
+            .checked_mul(NANOS_PER_SECOND)
+            .and_then(|n| n.checked_mul(SECS_PER_DAY as u128))
+            .ok_or(Error::Overflow)?;
+        total_nanos = total_nanos.checked_add(day_nanos).ok_or(Error::Overflow)?;
+        has_component = true;
+    }
+
+    if let Some(time_part) = time_part {
+        if time_part.is_empty() {
+            return Err(Error::Syntax(
+                "ISO 8601 duration has a 'T' separator but no time component".to_owned(),
+            ));
+        }
+        total_nanos = total_nanos
+            .checked_add(parse_time_part(time_part)?)
+            .ok_or(Error::Overflow)?;
+        has_component = true;
+    }
+
+    if !has_component {
+        return Err(Error::Syntax(
+            "ISO 8601 duration must contain at least one component".to_owned(),
+        ));
+    }
+
+    let secs = u64::try_from(total_nanos / NANOS_PER_SECOND).map_err(|_| Error::Overflow)?;
+    let subsec_nanos = (total_nanos % NANOS_PER_SECOND) as u32;
+    Ok(Duration::new(secs, subsec_nanos))
+}
+
+/// Parses a single `<digits><designator>` component, e.g. `"3D"`.
+fn parse_designator(s: &str, designator: char) -> Result<u64, Error> {
+    let digits = s
+        .strip_suffix(designator)
+        .ok_or_else(|| Error::Syntax(format!("Expected a '{}' component in '{}'", designator, s)))?;
+    digits
+        .parse()
+        .map_err(|_| Error::Syntax(format!("Invalid ISO 8601 component '{}'", s)))
+}
+
+/// Parses the `[nH][nM][nS]` time part, validating canonical ordering and
+/// accumulating the total in nanoseconds. Only the seconds component may be
+/// fractional.
+fn parse_time_part(s: &str) -> Result<u128, Error> {
+    const DESIGNATORS: [(char, u128); 3] =
+        [('H', NANOS_PER_HOUR), ('M', NANOS_PER_MINUTE), ('S', NANOS_PER_SECOND)];
+
+    let bytes = s.as_bytes();
+    let mut pos = 0;
+    let mut last_designator_index = None;
+    let mut total = 0u128;
+
+    while pos < bytes.len() {
+        let num_start = pos;
+        while pos < bytes.len() && (bytes[pos].is_ascii_digit() || bytes[pos] == b'.') {
+            pos += 1;
+        }
+        if pos == num_start || pos >= bytes.len() {
+            return Err(Error::Syntax(format!(
+                "Invalid ISO 8601 time component in '{}'",
+                s
+            )));
+        }
+        let number = &s[num_start..pos];
+        let designator = bytes[pos] as char;
+        let designator_index = DESIGNATORS
+            .iter()
+            .position(|(d, _)| *d == designator)
+            .ok_or_else(|| {
+                Error::Syntax(format!("Unknown ISO 8601 time designator '{}'", designator))
+            })?;
+        if matches!(last_designator_index, Some(last) if designator_index <= last) {
+            return Err(Error::Syntax(format!(
+                "ISO 8601 time designators must appear in order H, M, S in '{}'",
+                s
+            )));
+        }
+        if designator != 'S' && number.contains('.') {
+            return Err(Error::Syntax(format!(
+                "Only the seconds component may be fractional in '{}'",
+                s
+            )));
+        }
+        total = total
+            .checked_add(parse_fractional_to_nanos(
+                number,
+                DESIGNATORS[designator_index].1,
+            )?)
+            .ok_or(Error::Overflow)?;
+        last_designator_index = Some(designator_index);
+        pos += 1;
+    }
+
+    Ok(total)
+}
+
+/// Parses a (possibly fractional) decimal number and scales it by
+/// `unit_nanos`, rounding to the nearest nanosecond using integer math to
+/// avoid floating-point drift.
+fn parse_fractional_to_nanos(number: &str, unit_nanos: u128) -> Result<u128, Error> {
+    let (int_part, frac_part) = match number.split_once('.') {
+        Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+        None => (number, None),
+    };
+    let int_value: u128 = if int_part.is_empty() {
+        0
+    } else {
+        int_part
+            .parse()
+            .map_err(|_| Error::Syntax(format!("Invalid ISO 8601 numeric component '{}'", number)))?
+    };
+    let mut total = int_value.checked_mul(unit_nanos).ok_or(Error::Overflow)?;
+    if let Some(frac_part) = frac_part {
+        if frac_part.is_empty() || !frac_part.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(Error::Syntax(format!(
+                "Invalid ISO 8601 numeric component '{}'",
+                number
+            )));
+        }
+        let frac_value: u128 = frac_part.parse().map_err(|_| {
+            Error::Syntax(format!("Invalid ISO 8601 numeric component '{}'", number))
+        })?;
+        let scale = 10u128
+            .checked_pow(frac_part.len() as u32)
+            .ok_or(Error::Overflow)?;
+        total = total
+            .checked_add(checked_scaled_round(frac_value, unit_nanos, scale)?)
+            .ok_or(Error::Overflow)?;
+    }
+    Ok(total)
+}
+
+/// Deserializes an ISO 8601 duration string (e.g. `"PT1H30M"`) into a [`Duration`].
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    parse(&s).map_err(serde::de::Error::custom)
+}
+
+/// Serializes a [`Duration`] as an ISO 8601 duration string, e.g. `"PT1H30M"`.
+pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&format_iso8601(duration))
+}
+
+/// Formats a [`Duration`] in the `P[nD]T[nH][nM][nS]` ISO 8601 form,
+/// omitting zero components (but always emitting `PT0S` for a zero duration).
+fn format_iso8601(duration: &Duration) -> String {
+    let mut secs = duration.as_secs();
+    let nanos = duration.subsec_nanos();
+
+    let days = secs / SECS_PER_DAY;
+    secs %= SECS_PER_DAY;
+    let hours = secs / 3600;
+    secs %= 3600;
+    let minutes = secs / 60;
+    secs %= 60;
+
+    let mut out = String::from("P");
+    if days > 0 {
+        out.push_str(&format!("{}D", days));
+    }
+
+    let mut time = String::new();
+    if hours > 0 {
+        time.push_str(&format!("{}H", hours));
+    }
+    if minutes > 0 {
+        time.push_str(&format!("{}M", minutes));
+    }
+    if secs > 0 || nanos > 0 {
+        if nanos > 0 {
+            let fractional = format!("{:09}", nanos);
+            let fractional = fractional.trim_end_matches('0');
+            time.push_str(&format!("{}.{}S", secs, fractional));
+        } else {
+            time.push_str(&format!("{}S", secs));
+        }
+    }
+
+    if !time.is_empty() {
+        out.push('T');
+        out.push_str(&time);
+    }
+
+    if out == "P" {
+        out.push_str("T0S");
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Wrapper {
+        #[serde(with = "crate::iso8601")]
+        duration: Duration,
+    }
+
+    #[test]
+    fn test_parse_week_form() {
+        assert_eq!(parse("P1W").unwrap(), Duration::from_secs(SECS_PER_WEEK));
+        assert_eq!(
+            parse("P2W").unwrap(),
+            Duration::from_secs(2 * SECS_PER_WEEK)
+        );
+        assert!(parse("PW").is_err());
+        assert!(parse("P1WT1H").is_err());
+    }
+
+    #[test]
+    fn test_parse_date_and_time() {
+        assert_eq!(parse("PT1H30M").unwrap(), Duration::from_secs(5400));
+        assert_eq!(
+            parse("P3DT4H").unwrap(),
+            Duration::from_secs(3 * SECS_PER_DAY + 4 * 3600)
+        );
+        assert_eq!(parse("PT30S").unwrap(), Duration::from_secs(30));
+        assert_eq!(parse("PT1.5S").unwrap(), Duration::from_millis(1500));
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid() {
+        assert!(parse("1H30M").is_err());
+        assert!(parse("P").is_err());
+        assert!(parse("PT").is_err());
+        assert!(parse("PT1M30H").is_err());
+        assert!(parse("PT1H1H").is_err());
+        assert!(parse("P1.5D").is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_overflow_instead_of_panicking() {
+        assert_eq!(
+            parse("PT1.999999999999999999999999999999S"),
+            Err(Error::Overflow)
+        );
+        assert_eq!(parse("P99999999999999W"), Err(Error::Overflow));
+    }
+
+    #[test]
+    fn test_format_iso8601() {
+        let duration = Duration::from_secs(SECS_PER_WEEK + 2 * SECS_PER_DAY + 3 * 3600);
+        assert_eq!(format_iso8601(&duration), "P9DT3H");
+        assert_eq!(format_iso8601(&Duration::from_secs(0)), "PT0S");
+        assert_eq!(format_iso8601(&Duration::from_millis(1500)), "PT1.5S");
+    }
+
+    #[test]
+    fn test_deserialize_and_serialize_with_serde() {
+        let wrapper: Wrapper = serde_json::from_str("{\"duration\":\"PT1H30M\"}").unwrap();
+        assert_eq!(wrapper.duration, Duration::from_secs(5400));
+
+        let json = serde_json::to_string(&wrapper).unwrap();
+        assert_eq!(json, "{\"duration\":\"PT1H30M\"}");
+    }
+}
@@ -18,42 +18,108 @@
 //!
 //! # Usage
 //!
+//! The root `deserialize`/`serialize` functions accept the compound
+//! `"1h30m"` form (as well as a single `"30m"` segment) and are inverses of
+//! each other down to millisecond precision; `serialize` drops anything
+//! finer than a millisecond. If your config instead uses ISO 8601 / xsd:duration strings
+//! like `"PT1H30M"`, use the [`iso8601`] module's functions with
+//! `#[serde(with = "serde_duration_ext::iso8601")]` instead.
 
 mod durationunit;
 mod timetunit;
 
 pub mod error;
+pub mod iso8601;
 
 use std::time::Duration;
 
 pub use durationunit::*;
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serializer};
 pub use timetunit::*;
 
-// re-export chrono if the feature is enabled
-#[cfg(feature = "chrono")]
-pub use chrono;
-
+/// Deserializes a compound duration string (e.g. `"1h30m"` or `"30m"`) into
+/// a [`Duration`], the inverse of [`serialize`].
 pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
 where
     D: Deserializer<'de>,
 {
-    let duration_unit = DurationUnit::deserialize(deserializer)?;
-    Ok(duration_unit.into())
+    let compound = CompoundDuration::deserialize(deserializer)?;
+    Ok(compound.into_duration())
+}
+
+/// Serializes `duration` using the default [`DurationFormatter`] (smallest
+/// unit: milliseconds, anything finer is dropped), e.g. `"1h30m"`. Use
+/// `#[serde(serialize_with = "serde_duration_ext::serialize")]` to pair with
+/// [`deserialize`].
+pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&DurationFormatter::default().format(duration))
 }
 
+/// Serde support for `chrono::Duration`, which (unlike [`std::time::Duration`])
+/// is signed, so negative spans like `"-30m"` round-trip through this module.
 #[cfg(feature = "chrono")]
 pub mod chrono {
     use chrono::Duration;
-    use serde::{Deserializer, Deserialize};
+    use serde::{Deserialize, Deserializer, Serializer};
 
-    use crate::DateTimeUnit;
+    use crate::CompoundDuration;
 
     pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let datetime_unit = DateTimeUnit::deserialize(deserializer)?;
-        Ok(datetime_unit.into())
+        let s = String::deserialize(deserializer)?;
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.as_str()),
+        };
+        let compound: CompoundDuration = unsigned.parse().map_err(serde::de::Error::custom)?;
+        let magnitude =
+            Duration::from_std(compound.into_duration()).map_err(serde::de::Error::custom)?;
+        Ok(if negative { -magnitude } else { magnitude })
+    }
+
+    pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let negative = *duration < Duration::zero();
+        let magnitude = if negative { -*duration } else { *duration };
+        let std_duration = magnitude
+            .to_std()
+            .map_err(serde::ser::Error::custom)?;
+        let mut formatted = crate::DurationFormatter::default().format(&std_duration);
+        if negative {
+            formatted.insert(0, '-');
+        }
+        serializer.serialize_str(&formatted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Wrapper {
+        #[serde(with = "crate")]
+        duration: Duration,
+    }
+
+    #[test]
+    fn test_root_deserialize_and_serialize_round_trip_through_serde() {
+        let wrapper: Wrapper = serde_json::from_str("{\"duration\":\"1h30m15s\"}").unwrap();
+        assert_eq!(wrapper.duration, Duration::from_secs(5415));
+
+        let json = serde_json::to_string(&wrapper).unwrap();
+        assert_eq!(json, "{\"duration\":\"1h30m15s\"}");
+
+        let round_tripped: Wrapper = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_tripped, wrapper);
     }
 }
@@ -12,6 +12,29 @@ pub enum TimeUnit {
     Hour,
     Day,
     Week,
+    Month,
+    Year,
+    Century,
+}
+
+impl TimeUnit {
+    /// Returns the canonical short suffix used when formatting this unit
+    /// (the same spelling accepted by [`FromStr`]).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimeUnit::Nanosecond => "ns",
+            TimeUnit::Microsecond => "us",
+            TimeUnit::Millisecond => "ms",
+            TimeUnit::Second => "s",
+            TimeUnit::Minute => "m",
+            TimeUnit::Hour => "h",
+            TimeUnit::Day => "d",
+            TimeUnit::Week => "w",
+            TimeUnit::Month => "mo",
+            TimeUnit::Year => "y",
+            TimeUnit::Century => "c",
+        }
+    }
 }
 
 impl FromStr for TimeUnit {
@@ -27,6 +50,9 @@ impl FromStr for TimeUnit {
             "h" | "hour" | "hours" => Ok(TimeUnit::Hour),
             "d" | "day" | "days" => Ok(TimeUnit::Day),
             "w" | "week" | "weeks" => Ok(TimeUnit::Week),
+            "mo" | "month" | "months" => Ok(TimeUnit::Month),
+            "y" | "year" | "years" => Ok(TimeUnit::Year),
+            "c" | "century" => Ok(TimeUnit::Century),
             _ => Err(Error::UnitNotSupported(format!(
                 "Unit '{}' not supported",
                 s
@@ -78,9 +104,35 @@ mod tests {
         assert_eq!("week".parse(), Ok(TimeUnit::Week));
         assert_eq!("weeks".parse(), Ok(TimeUnit::Week));
 
+        assert_eq!("mo".parse(), Ok(TimeUnit::Month));
+        assert_eq!("month".parse(), Ok(TimeUnit::Month));
+        assert_eq!("months".parse(), Ok(TimeUnit::Month));
+
+        assert_eq!("y".parse(), Ok(TimeUnit::Year));
+        assert_eq!("year".parse(), Ok(TimeUnit::Year));
+        assert_eq!("years".parse(), Ok(TimeUnit::Year));
+
+        assert_eq!("c".parse(), Ok(TimeUnit::Century));
+        assert_eq!("century".parse(), Ok(TimeUnit::Century));
+
         assert!("foo".parse::<TimeUnit>().is_err());
     }
 
+    #[test]
+    fn test_as_str() {
+        assert_eq!(TimeUnit::Nanosecond.as_str(), "ns");
+        assert_eq!(TimeUnit::Microsecond.as_str(), "us");
+        assert_eq!(TimeUnit::Millisecond.as_str(), "ms");
+        assert_eq!(TimeUnit::Second.as_str(), "s");
+        assert_eq!(TimeUnit::Minute.as_str(), "m");
+        assert_eq!(TimeUnit::Hour.as_str(), "h");
+        assert_eq!(TimeUnit::Day.as_str(), "d");
+        assert_eq!(TimeUnit::Week.as_str(), "w");
+        assert_eq!(TimeUnit::Month.as_str(), "mo");
+        assert_eq!(TimeUnit::Year.as_str(), "y");
+        assert_eq!(TimeUnit::Century.as_str(), "c");
+    }
+
     #[test]
     fn test_ord() {
         assert!(TimeUnit::Nanosecond < TimeUnit::Microsecond);
@@ -90,5 +142,8 @@ mod tests {
         assert!(TimeUnit::Minute < TimeUnit::Hour);
         assert!(TimeUnit::Hour < TimeUnit::Day);
         assert!(TimeUnit::Day < TimeUnit::Week);
+        assert!(TimeUnit::Week < TimeUnit::Month);
+        assert!(TimeUnit::Month < TimeUnit::Year);
+        assert!(TimeUnit::Year < TimeUnit::Century);
     }
 }